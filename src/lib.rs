@@ -90,6 +90,78 @@ pub trait Also {
         f(&mut self).map(|_| self)
     }
 
+    /// Returns the receiver if the given function returns `Err`, else forwards the `Ok` value as
+    /// the rejected signal. The mirror image of [`take_if`](Also::take_if).
+    /// Akin to Kotlin's [takeUnless](https://kotlinlang.org/api/latest/jvm/stdlib/kotlin/take-unless.html)
+    /// extension function
+    /// # Examples
+    /// ```
+    /// # use also::*;
+    /// let x = "aa".take_unless(|x| u8::from_str_radix(x, 10));
+    /// assert_eq!(Ok("aa"), x);
+    ///
+    /// let x = "42".take_unless(|x| u8::from_str_radix(x, 10));
+    /// assert_eq!(Err(42), x);
+    /// ```
+    #[inline(always)]
+    fn take_unless<R, E>(mut self, f: impl FnOnce(&mut Self) -> Result<R, E>) -> Result<Self, R>
+    where
+        Self: Sized,
+    {
+        match f(&mut self) {
+            Ok(r) => Err(r),
+            Err(_) => Ok(self),
+        }
+    }
+
+    /// Returns `Some(self)` if the given predicate returns `true`, else `None`.
+    /// The boolean/[`Option`] analogue of [`take_if`](Also::take_if), matching Kotlin's
+    /// [takeIf](https://kotlinlang.org/api/latest/jvm/stdlib/kotlin/take-if.html) semantics.
+    /// # Examples
+    /// ```
+    /// # use also::*;
+    /// let x = "42".filter_if(|x| x.chars().all(|c| c.is_ascii_digit()));
+    /// assert_eq!(Some("42"), x);
+    ///
+    /// let x = "aa".filter_if(|x| x.chars().all(|c| c.is_ascii_digit()));
+    /// assert_eq!(None, x);
+    /// ```
+    #[inline(always)]
+    fn filter_if(self, f: impl FnOnce(&Self) -> bool) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if f(&self) {
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `Some(self)` if the given predicate returns `false`, else `None`.
+    /// The boolean/[`Option`] analogue of [`take_unless`](Also::take_unless), matching Kotlin's
+    /// [takeUnless](https://kotlinlang.org/api/latest/jvm/stdlib/kotlin/take-unless.html) semantics.
+    /// # Examples
+    /// ```
+    /// # use also::*;
+    /// let x = "aa".filter_unless(|x| x.chars().all(|c| c.is_ascii_digit()));
+    /// assert_eq!(Some("aa"), x);
+    ///
+    /// let x = "42".filter_unless(|x| x.chars().all(|c| c.is_ascii_digit()));
+    /// assert_eq!(None, x);
+    /// ```
+    #[inline(always)]
+    fn filter_unless(self, f: impl FnOnce(&Self) -> bool) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if f(&self) {
+            None
+        } else {
+            Some(self)
+        }
+    }
+
     /// Calls a function with the receiver, and returns the receiver.
     /// Akin to Kotlin's [also](https://kotlinlang.org/api/latest/jvm/stdlib/kotlin/also.html)
     /// extension function
@@ -108,6 +180,49 @@ pub trait Also {
         self
     }
 
+    /// Calls a function with the receiver when `self` equals `expected`, then returns the receiver.
+    /// Fills the gap between [`also`](Also::also) (always runs) and [`take_if`](Also::take_if)
+    /// (propagates an error), inspired by the (then-unstable) `Option::contains`/`Result::contains`.
+    /// # Examples
+    /// ```
+    /// # use also::*;
+    /// let x = 1.also_if_eq(&1, |x| *x += 10);
+    /// assert_eq!(11, x);
+    ///
+    /// let x = 1.also_if_eq(&2, |x| *x += 10);
+    /// assert_eq!(1, x);
+    /// ```
+    #[inline(always)]
+    fn also_if_eq<U: PartialEq<Self>>(mut self, expected: &U, f: impl FnOnce(&mut Self)) -> Self
+    where
+        Self: Sized,
+    {
+        if expected == &self {
+            f(&mut self);
+        }
+        self
+    }
+
+    /// Invokes `f(&mut self, i)` for each `i` in `0..n`, then returns the receiver.
+    /// Akin to Kotlin's [repeat](https://kotlinlang.org/api/latest/jvm/stdlib/kotlin/repeat.html)
+    /// function, letting accumulators and builders fold repeated mutation into a chain.
+    /// # Examples
+    /// ```
+    /// # use also::*;
+    /// let v = Vec::new().repeat(3, |v, i| v.push(i));
+    /// assert_eq!(vec![0, 1, 2], v);
+    /// ```
+    #[inline(always)]
+    fn repeat(mut self, n: usize, mut f: impl FnMut(&mut Self, usize)) -> Self
+    where
+        Self: Sized,
+    {
+        for i in 0..n {
+            f(&mut self, i);
+        }
+        self
+    }
+
     /// Calls a function with the `Ok` contained value and returns the `Result`.
     /// # Examples
     /// ```
@@ -154,3 +269,85 @@ pub trait Also {
 }
 
 impl<T> Also for T {}
+
+/// Stable equivalents of [`Also::and_run`]/[`Also::or_run`] for [`Result`], available without the
+/// `nightly` feature.
+pub trait ResultAlso<T, E> {
+    /// Runs `f` on the contained `Ok` value by `&mut`, leaves `Err` untouched, and returns `self`.
+    /// # Examples
+    /// ```
+    /// # use also::*;
+    /// let x: Result<String, ()> = Ok("Hello".to_string()).and_run(|s| s.push('!'));
+    /// assert_eq!(Ok("Hello!".to_string()), x);
+    /// ```
+    fn and_run(self, f: impl FnOnce(&mut T)) -> Result<T, E>;
+
+    /// Runs `f` on the contained `Err` value by `&mut`, leaves `Ok` untouched, and returns `self`.
+    /// # Examples
+    /// ```
+    /// # use also::*;
+    /// let x: Result<(), String> = Err("Hello".to_string()).or_run(|s| s.push('!'));
+    /// assert_eq!(Err("Hello!".to_string()), x);
+    /// ```
+    fn or_run(self, f: impl FnOnce(&mut E)) -> Result<T, E>;
+}
+
+impl<T, E> ResultAlso<T, E> for Result<T, E> {
+    #[inline(always)]
+    fn and_run(mut self, f: impl FnOnce(&mut T)) -> Result<T, E> {
+        if let Ok(r) = &mut self {
+            f(r);
+        }
+        self
+    }
+
+    #[inline(always)]
+    fn or_run(mut self, f: impl FnOnce(&mut E)) -> Result<T, E> {
+        if let Err(e) = &mut self {
+            f(e);
+        }
+        self
+    }
+}
+
+/// Stable equivalents of [`Also::and_run`]/[`Also::or_run`] for [`Option`], available without the
+/// `nightly` feature.
+pub trait OptionAlso<T> {
+    /// Runs `f` on the contained `Some` value by `&mut`, leaves `None` untouched, and returns `self`.
+    /// # Examples
+    /// ```
+    /// # use also::*;
+    /// let x = Some("Hello".to_string()).and_run(|s| s.push('!'));
+    /// assert_eq!(Some("Hello!".to_string()), x);
+    /// ```
+    fn and_run(self, f: impl FnOnce(&mut T)) -> Option<T>;
+
+    /// Runs `f` on the `None` branch, leaves `Some` untouched, and returns `self`.
+    /// # Examples
+    /// ```
+    /// # use also::*;
+    /// let mut ran = false;
+    /// let x: Option<()> = None.or_run(|| ran = true);
+    /// assert_eq!(None, x);
+    /// assert!(ran);
+    /// ```
+    fn or_run(self, f: impl FnOnce()) -> Option<T>;
+}
+
+impl<T> OptionAlso<T> for Option<T> {
+    #[inline(always)]
+    fn and_run(mut self, f: impl FnOnce(&mut T)) -> Option<T> {
+        if let Some(r) = &mut self {
+            f(r);
+        }
+        self
+    }
+
+    #[inline(always)]
+    fn or_run(self, f: impl FnOnce()) -> Option<T> {
+        if self.is_none() {
+            f();
+        }
+        self
+    }
+}